@@ -1,19 +1,66 @@
 use std:: {
-    io::{self, Write},
+    io::{self, IsTerminal, Write},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
     fs,
 };
 use colored::Colorize;
-use chrono::{NaiveDate, Local, ParseError};
+use chrono::{Datelike, NaiveDate, Local, ParseError};
 use indicatif::{ProgressBar, ProgressStyle};
-use dirs;
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
+use clap::Parser;
+
+/// Total wall-clock time budgeted for each animated sequence (the weeks grid, the
+/// progress bar), regardless of how many rows/steps it has to render.
+const ANIMATION_BUDGET: Duration = Duration::from_millis(1500);
+
+/// The smallest and largest expected lifespan (in years) we'll accept, from either
+/// `--expected-years` or `config.toml`. Keeps implausible values (zero, negative, or
+/// huge) from reaching the `chrono` date math and progress bar sizing downstream.
+const MIN_EXPECTED_YEARS: i64 = 1;
+const MAX_EXPECTED_YEARS: i64 = 150;
 
 #[derive(Deserialize, Debug)]
 struct UserConfig {
     birthday: Option<String>,
     show_weeks: Option<bool>,
+    show_biorhythm: Option<bool>,
+    biorhythm_window: Option<i32>,
+    expected_years: Option<i32>,
+    remaining_bar: Option<bool>,
+}
+
+/// Command-line arguments accepted by `yliw`.
+///
+/// CLI flags take precedence over `config.toml`, which in turn takes precedence over
+/// the interactive birthday prompt. This lets the tool run non-interactively in scripts
+/// and pipelines.
+#[derive(Parser, Debug)]
+#[command(name = "yliw", about = "Visualize your life in weeks")]
+struct Cli {
+    /// Birthday in DD-MM-YYYY format. Bypasses the interactive prompt and config file.
+    #[arg(long)]
+    birthday: Option<String>,
+
+    /// Override the expected lifespan in years (default: 90).
+    #[arg(long = "expected-years", value_parser = clap::value_parser!(i32).range(MIN_EXPECTED_YEARS..=MAX_EXPECTED_YEARS))]
+    expected_years: Option<i32>,
+
+    /// Skip all animation delays, for fast or non-TTY use.
+    #[arg(long = "no-animation")]
+    no_animation: bool,
+
+    /// Force-enable the life-in-weeks grid, overriding config.toml.
+    #[arg(long = "weeks", conflicts_with = "no_weeks")]
+    weeks: bool,
+
+    /// Force-disable the life-in-weeks grid, overriding config.toml.
+    #[arg(long = "no-weeks")]
+    no_weeks: bool,
+
+    /// Print a single JSON object instead of the animated view.
+    #[arg(long = "json")]
+    json: bool,
 }
 
 /// Prints a visual representation of life in weeks.
@@ -24,16 +71,19 @@ struct UserConfig {
 /// # Arguments
 ///
 /// * `lived_weeks` - The number of weeks lived. This will determine how many cells are marked in green.
+/// * `animate` - Whether to pace rendering across `budget`. Pass `false` to render instantly.
+/// * `budget` - The total time the animation is allotted to run, split evenly across rows.
 ///
 /// # Examples
 ///
 /// ```
 /// // Assuming the use of the colored and time crates
+/// use std::time::Duration;
 /// use yliw::print_life_in_weeks;
 ///
 /// // Print a representation for a 25-year-old person
 /// // (25 years * 52 weeks/year)
-/// print_life_in_weeks(25 * 52);
+/// print_life_in_weeks(25 * 52, true, Duration::from_millis(1500));
 /// ```
 ///
 /// # Panics
@@ -42,17 +92,18 @@ struct UserConfig {
 ///
 /// # Errors
 ///
-/// This function does not return errors. However, it sleeps for 20ms after printing each row,
-/// which could slightly delay program execution.
+/// This function does not return errors. However, when `animate` is `true` it sleeps
+/// between rows to stay on pace with `budget`, which could slightly delay program execution.
 ///
 /// # Notes
 ///
 /// This function is primarily for visual representation and does not return any value.
-fn print_life_in_weeks(lived_weeks: usize){
+fn print_life_in_weeks(lived_weeks: usize, animate: bool, budget: Duration){
     let rows = 30;
     let columns = 156;
     let pattern = "=";
-    
+    let start = Instant::now();
+
     println!("\n{}\n", "Your life in weeks:".bold());
     for row in 0..rows {
         for col in 0..columns {
@@ -64,8 +115,125 @@ fn print_life_in_weeks(lived_weeks: usize){
                 print!("{}", pattern.cyan());
             }
         }
-        println!(); 
-        thread::sleep(Duration::from_millis(20));
+        println!();
+        if animate {
+            pace_step(start, row, rows, budget);
+        }
+    }
+}
+
+/// Computes the classic three biorhythm curves for a given age.
+///
+/// The biorhythm theory models mood and ability as sinusoidal cycles counted in days
+/// since birth: a 23-day physical cycle, a 28-day emotional cycle, and a 33-day
+/// intellectual cycle. Each curve is rescaled from `sin` into the 0-100 range, with
+/// 50 representing the neutral midline.
+///
+/// # Arguments
+///
+/// * `age_in_days` - The subject's age in days, as returned by `get_age_in_days`.
+///
+/// # Returns
+///
+/// A tuple `(physical, emotional, intellectual)` with each value in the range 0-100.
+///
+/// # Examples
+///
+/// ```
+/// use yliw::biorhythm_positions;
+///
+/// let (physical, emotional, intellectual) = biorhythm_positions(10000);
+/// assert!(physical >= 0.0 && physical <= 100.0);
+/// ```
+fn biorhythm_positions(age_in_days: i32) -> (f64, f64, f64) {
+    let z = age_in_days as f64;
+    let physical = 50.0 * (1.0 + (2.0 * std::f64::consts::PI * z / 23.0).sin());
+    let emotional = 50.0 * (1.0 + (2.0 * std::f64::consts::PI * z / 28.0).sin());
+    let intellectual = 50.0 * (1.0 + (2.0 * std::f64::consts::PI * z / 33.0).sin());
+    (physical, emotional, intellectual)
+}
+
+/// Prints a visual representation of the physical, emotional, and intellectual
+/// biorhythm cycles for a window of days centered on today.
+///
+/// Each cycle is rendered as a single colored row, with one column per day in the
+/// window. The midline (50%) is marked with `|` and today's column is marked with `^`.
+/// Days where a curve crosses the midline between one day and the next are reported
+/// below the chart as "critical days", since biorhythm theory treats those crossings
+/// as the most volatile points in each cycle.
+///
+/// # Arguments
+///
+/// * `age_in_days` - The subject's age in days today, as returned by `get_age_in_days`.
+/// * `window_days` - The number of days to plot, centered on today.
+///
+/// # Examples
+///
+/// ```
+/// use yliw::print_biorhythm;
+///
+/// print_biorhythm(10000, 30);
+/// ```
+///
+/// # Panics
+///
+/// This function will not panic under normal circumstances.
+fn print_biorhythm(age_in_days: i32, window_days: i32) {
+    let half = window_days / 2;
+    let start = age_in_days - half;
+    let end = start + window_days - 1;
+
+    println!("\n{}\n", "Your biorhythms:".bold());
+
+    type BiorhythmCycle = (&'static str, fn(i32) -> f64);
+    let cycles: [BiorhythmCycle; 3] = [
+        ("Physical", |z| biorhythm_positions(z).0),
+        ("Emotional", |z| biorhythm_positions(z).1),
+        ("Intellectual", |z| biorhythm_positions(z).2),
+    ];
+
+    for (label, value_at) in cycles.iter() {
+        print!("{:<14}", label);
+        for day in start..=end {
+            let value = value_at(day);
+            let marker = if day == age_in_days {
+                "^"
+            } else if (value - 50.0).abs() < 1.0 {
+                "|"
+            } else {
+                "="
+            };
+            if day == age_in_days {
+                print!("{}", marker.yellow());
+            } else if value >= 50.0 {
+                print!("{}", marker.green());
+            } else {
+                print!("{}", marker.cyan());
+            }
+        }
+        println!();
+    }
+
+    let mut critical_days: Vec<i32> = Vec::new();
+    for (_, value_at) in cycles.iter() {
+        let mut previous_sign = (value_at(start) - 50.0).signum();
+        for day in (start + 1)..=end {
+            let sign = (value_at(day) - 50.0).signum();
+            if sign != previous_sign {
+                critical_days.push(day - age_in_days);
+            }
+            previous_sign = sign;
+        }
+    }
+    critical_days.sort_unstable();
+    critical_days.dedup();
+
+    if !critical_days.is_empty() {
+        let formatted: Vec<String> = critical_days
+            .iter()
+            .map(|offset| format!("{:+}", offset))
+            .collect();
+        println!("\nCritical days (relative to today): {}", formatted.join(", ").magenta());
     }
 }
 
@@ -104,6 +272,37 @@ fn parse_date(input: &str) -> Result<NaiveDate, ParseError> {
     NaiveDate::parse_from_str(input, "%d-%m-%Y")
 }
 
+/// Sleep just long enough to keep a multi-step animation on pace with its total budget.
+///
+/// The budget is split evenly across `total_steps`, giving a target elapsed time for
+/// each step. If the animation is already running behind that target — because the
+/// terminal is slow to render, or earlier steps overshot — no sleep happens, so the
+/// pacing itself never pushes the animation past its budget.
+///
+/// # Arguments
+///
+/// * `start` - When the animation began.
+/// * `step` - The step that was just completed, zero-indexed.
+/// * `total_steps` - The total number of steps in the animation.
+/// * `budget` - The total time the animation is allotted to run.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use yliw::pace_step;
+///
+/// let start = Instant::now();
+/// pace_step(start, 0, 10, Duration::from_millis(100));
+/// ```
+fn pace_step(start: Instant, step: usize, total_steps: usize, budget: Duration) {
+    let total_steps = total_steps.max(1) as u32;
+    let target_elapsed = (budget / total_steps) * (step as u32 + 1);
+    if let Some(remaining) = target_elapsed.checked_sub(start.elapsed()) {
+        thread::sleep(remaining);
+    }
+}
+
 /// Display a welcome message.
 /// 
 /// This function prints a welcome message to the console, emphasizing it with green color.
@@ -145,7 +344,6 @@ fn get_user_birthday() -> String {
 ///
 /// This function takes a string representing a birthday in the format "DD-MM-YYYY".
 /// It parses the input birthday string and calculates the age in days relative to the current date.
-/// If the parsing fails, it prints an error message and exits the program.
 ///
 /// # Arguments
 ///
@@ -155,38 +353,119 @@ fn get_user_birthday() -> String {
 ///
 /// The age in days as an integer (`i32`) if parsing is successful.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function will panic if parsing of the input date fails.
+/// Returns the underlying `ParseError` if `birthday` does not conform to `DD-MM-YYYY`.
+/// Callers decide how to report this (interactively or as part of `--json` output)
+/// rather than this function printing or exiting directly.
 ///
 /// # Examples
 ///
 /// ```
-/// assert_eq!(get_age_in_days("01-01-1990"), 12053);
+/// // Measured against today's date: 12053 when run on 01-01-2023.
+/// let age_in_days = get_age_in_days("01-01-1990").unwrap();
 /// ```
-fn get_age_in_days(birthday: &str) -> i32{
-    let result = match parse_date(&birthday.trim()){
-        Ok(input_date) => {
-            let current_date = Local::now().naive_local().date();
-            let duration = current_date.signed_duration_since(input_date);
-            duration.num_days() as i32
-        },
-        Err(_) => {
-            println!("Unable to parse the date. Please use DD-MM-YYYY.");
-            std::process::exit(1);
-        },
-    };
-    result
+fn get_age_in_days(birthday: &str) -> Result<i32, ParseError> {
+    let input_date = parse_date(birthday.trim())?;
+    let current_date = Local::now().naive_local().date();
+    let duration = current_date.signed_duration_since(input_date);
+    Ok(duration.num_days() as i32)
+}
+
+/// Clamp an expected-lifespan value to `MIN_EXPECTED_YEARS..=MAX_EXPECTED_YEARS`.
+///
+/// `--expected-years` is already range-validated by `clap`, but `config.toml` is
+/// read as a plain `i32` with no such check, so implausible values (zero, negative,
+/// or huge) are clamped here before they reach `add_years`/`create_progress_bar`.
+///
+/// # Arguments
+///
+/// * `years` - The expected lifespan in years, from any source.
+///
+/// # Returns
+///
+/// `years` clamped to the supported range.
+///
+/// # Examples
+///
+/// ```
+/// use yliw::clamp_expected_years;
+///
+/// assert_eq!(clamp_expected_years(-5), 1);
+/// assert_eq!(clamp_expected_years(90), 90);
+/// assert_eq!(clamp_expected_years(i32::MAX), 150);
+/// ```
+fn clamp_expected_years(years: i32) -> i32 {
+    years.clamp(MIN_EXPECTED_YEARS as i32, MAX_EXPECTED_YEARS as i32)
+}
+
+/// Add a number of years to a date, handling the Feb 29 edge case.
+///
+/// If the resulting month/day does not exist in the target year (a Feb 29 birthday
+/// landing on a non-leap year), this falls back to Feb 28 rather than panicking.
+///
+/// # Arguments
+///
+/// * `date` - The starting date.
+/// * `years` - The number of years to add.
+///
+/// # Returns
+///
+/// The resulting `NaiveDate`, `years` years after `date`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use yliw::add_years;
+///
+/// let leap_birthday = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+/// assert_eq!(add_years(leap_birthday, 1), NaiveDate::from_ymd_opt(2001, 2, 28).unwrap());
+/// ```
+fn add_years(date: NaiveDate, years: i32) -> NaiveDate {
+    let target_year = date.year() + years;
+    NaiveDate::from_ymd_opt(target_year, date.month(), date.day())
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(target_year, 2, 28).expect("Feb 28 is always valid"))
+}
+
+/// Calculate the number of days in a projected lifespan, starting from a birth date.
+///
+/// Unlike a flat `expected_years * 365`, this adds calendar years to the actual birth
+/// date, so leap years between birth and the projected end of life are accounted for.
+///
+/// # Arguments
+///
+/// * `birth_date` - The subject's date of birth.
+/// * `expected_years` - The projected lifespan in years.
+///
+/// # Returns
+///
+/// The number of days between `birth_date` and the projected end of life.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use yliw::calculate_expected_days;
+///
+/// let birth_date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+/// assert_eq!(calculate_expected_days(birth_date, 90), 32872);
+/// ```
+fn calculate_expected_days(birth_date: NaiveDate, expected_years: i32) -> i32 {
+    let end_of_life = add_years(birth_date, expected_years);
+    end_of_life.signed_duration_since(birth_date).num_days() as i32
 }
 
 /// Create a progress bar with a specified total.
 ///
 /// This function creates and configures a progress bar with the specified total steps.
 /// It sets a custom style for the progress bar, including the template and progress characters.
+/// When `remaining` is `true`, the bar is styled to read as depleting rather than filling up.
 ///
 /// # Arguments
 ///
 /// * `total` - The total number of steps for the progress bar.
+/// * `remaining` - Whether this bar will be driven in "time remaining" mode.
 ///
 /// # Returns
 ///
@@ -199,14 +478,19 @@ fn get_age_in_days(birthday: &str) -> i32{
 /// # Examples
 ///
 /// ```
-/// let progress_bar = create_progress_bar(100);
+/// let progress_bar = create_progress_bar(100, false);
 /// progress_bar.set_message("Processing");
 /// progress_bar.inc(10);
 /// ```
-fn create_progress_bar(total: u64) -> ProgressBar {
+fn create_progress_bar(total: u64, remaining: bool) -> ProgressBar {
     let progress_bar = ProgressBar::new(total);
+    let template = if remaining {
+        "[{bar:70.cyan/green}]"
+    } else {
+        "[{bar:70.green/cyan}]"
+    };
     let style = ProgressStyle::default_bar()
-                            .template("[{bar:70.green/cyan}]")
+                            .template(template)
                             .expect("Error parsing progress bar template")
                             .progress_chars("=>-");
     progress_bar.set_style(style);
@@ -215,29 +499,88 @@ fn create_progress_bar(total: u64) -> ProgressBar {
 
 /// Display a progress bar representing the passage of years.
 ///
-/// This function updates a given progress bar to reflect the passage of years up to the specified age.
-/// It sets the position of the progress bar to represent each year and pauses briefly to simulate progress.
+/// This function updates a given progress bar to reflect the passage of years up to the
+/// specified age, pausing briefly to simulate progress. In the default mode the bar
+/// fills up from empty as years are lived. In "time remaining" mode the bar instead
+/// starts full and depletes toward the projected end of life.
 ///
 /// # Arguments
 ///
 /// * `progress_bar` - A mutable reference to a `ProgressBar` instance to be updated.
 /// * `age_in_years` - The age in years to represent with the progress bar.
+/// * `animate` - Whether to pace rendering across `budget`. Pass `false` to render instantly.
+/// * `remaining` - Whether to render the bar depleting from full instead of filling up.
+/// * `budget` - The total time the animation is allotted to run, split evenly across years.
 ///
 /// # Examples
 ///
 /// ```
+/// use std::time::Duration;
 /// use indicatif::ProgressBar;
 /// let mut pb = ProgressBar::new(10);
-/// display_progress_bar(&mut pb, 5);
+/// display_progress_bar(&mut pb, 5, true, false, Duration::from_millis(1500));
 /// ```
-fn display_progress_bar(progress_bar :&mut  ProgressBar, age_in_years: i32) {
-    for year in 0..=age_in_years {
-        progress_bar.set_position(year as u64);
-        thread::sleep(Duration::from_millis(20));
+fn display_progress_bar(progress_bar :&mut  ProgressBar, age_in_years: i32, animate: bool, remaining: bool, budget: Duration) {
+    let total = progress_bar.length().unwrap_or(age_in_years as u64);
+    let total_steps = age_in_years as usize + 1;
+    let start = Instant::now();
+    for (step, year) in (0..=age_in_years).enumerate() {
+        let position = if remaining {
+            total.saturating_sub(year as u64)
+        } else {
+            year as u64
+        };
+        progress_bar.set_position(position);
+        if animate {
+            pace_step(start, step, total_steps, budget);
+        }
     }
     println!();
 }
 
+/// The life statistics derived from a subject's age and expected lifespan.
+///
+/// This is the shared set of numbers behind both the human-readable summary message
+/// and the `--json` output mode, so the two presentations never drift apart.
+#[derive(Serialize)]
+struct LifeStats {
+    remaining_days: i32,
+    remaining_weeks: i32,
+    remaining_years: i32,
+    completion_percent: f64,
+}
+
+/// Calculate remaining days/weeks/years and completion percentage.
+///
+/// # Arguments
+///
+/// * `expected_days` - The total number of expected days.
+/// * `age_in_days` - The number of days lived so far.
+///
+/// # Returns
+///
+/// A `LifeStats` with the remaining time broken down by unit and the completion percentage.
+///
+/// # Examples
+///
+/// ```
+/// let stats = calculate_life_stats(36500, 18250);
+/// assert_eq!(stats.remaining_days, 18250);
+/// ```
+fn calculate_life_stats(expected_days: i32, age_in_days: i32) -> LifeStats {
+    let remaining_days = expected_days - age_in_days;
+    let remaining_years = remaining_days / 365;
+    let remaining_weeks = remaining_years * 52;
+    let completion_percent = (age_in_days as f64 / expected_days as f64) * 100.0;
+
+    LifeStats {
+        remaining_days,
+        remaining_weeks,
+        remaining_years,
+        completion_percent,
+    }
+}
+
 /// Display a summary message based on expected and remaining days.
 ///
 /// This function calculates and displays a summary message based on the expected
@@ -256,25 +599,113 @@ fn display_progress_bar(progress_bar :&mut  ProgressBar, age_in_years: i32) {
 /// display_summary_message(36500, 18250);
 /// ```
 fn display_summary_message(expected_days: i32, age_in_days: i32){
-    let remaining_days = expected_days - age_in_days;
-    let remaining_years = remaining_days / 365;
-    let remaining_weeks = remaining_years * 52;
-    let completion_percent = (age_in_days as f64 / expected_days as f64) * 100.0;
+    let stats = calculate_life_stats(expected_days, age_in_days);
 
      let message = format!(
         "\n\n{}\n\nLooking ahead, here's what's still in store for you:\n\n\
         - Celebrate: {} more birthdays\n\
         - Relax: {} more weekends\n\
         - Enjoy: {} more breakfasts\n",
-        format!("Your life is {:.2}% complete!", completion_percent).green().bold(),
-        format!("{} wonderful", remaining_years).yellow(),
-        format!("{} relaxing", remaining_weeks).cyan(),
-        format!("{} delicious", remaining_days).magenta()
+        format!("Your life is {:.2}% complete!", stats.completion_percent).green().bold(),
+        format!("{} wonderful", stats.remaining_years).yellow(),
+        format!("{} relaxing", stats.remaining_weeks).cyan(),
+        format!("{} delicious", stats.remaining_days).magenta()
     );
 
     println!("{}", message);
 }
 
+/// A machine-readable snapshot of everything `main` computes about the subject's life.
+///
+/// Serialized to a single JSON object by `print_json_snapshot` when `--json` is passed,
+/// so `yliw` can feed dashboards and status bars instead of only an animated TTY view.
+#[derive(Serialize)]
+struct LifeSnapshot {
+    age_in_days: i32,
+    age_in_weeks: f64,
+    age_in_years: i32,
+    completion_percent: f64,
+    remaining_days: i32,
+    remaining_weeks: i32,
+    remaining_years: i32,
+    expected_years: i32,
+}
+
+/// Print a `LifeSnapshot` to stdout as a single JSON object.
+///
+/// # Arguments
+///
+/// * `snapshot` - The life snapshot to serialize.
+///
+/// # Panics
+///
+/// This function will panic if the snapshot cannot be serialized, which should not
+/// happen for this plain-data struct.
+///
+/// # Examples
+///
+/// ```
+/// let snapshot = LifeSnapshot {
+///     age_in_days: 10000,
+///     age_in_weeks: 1428.57,
+///     age_in_years: 27,
+///     completion_percent: 30.42,
+///     remaining_days: 22872,
+///     remaining_weeks: 3224,
+///     remaining_years: 62,
+///     expected_years: 90,
+/// };
+/// print_json_snapshot(&snapshot);
+/// ```
+fn print_json_snapshot(snapshot: &LifeSnapshot) {
+    let json = serde_json::to_string(snapshot).expect("Failed to serialize life snapshot");
+    println!("{}", json);
+}
+
+/// Apply CLI-flag overrides on top of a loaded (or default) `UserConfig`.
+///
+/// CLI flags take precedence over `config.toml`: a field is only overridden when the
+/// corresponding flag was actually passed, so unset flags leave the config value (or
+/// its default) untouched. This does not resolve the interactive birthday prompt,
+/// since that's an I/O side effect handled separately in `main`.
+///
+/// # Arguments
+///
+/// * `cli` - The parsed command-line arguments.
+/// * `config` - The `UserConfig` loaded from `config.toml`, or the default if absent.
+///
+/// # Returns
+///
+/// The `UserConfig` with CLI overrides applied.
+///
+/// # Examples
+///
+/// ```
+/// use yliw::{apply_cli_overrides, Cli, UserConfig};
+///
+/// let cli = Cli::parse_from(["yliw", "--expected-years", "80"]);
+/// let config = UserConfig { birthday: None, show_weeks: Some(true), show_biorhythm: Some(false), biorhythm_window: Some(30), expected_years: Some(90), remaining_bar: Some(false) };
+/// let resolved = apply_cli_overrides(&cli, config);
+/// assert_eq!(resolved.expected_years, Some(80));
+/// ```
+fn apply_cli_overrides(cli: &Cli, mut config: UserConfig) -> UserConfig {
+    if let Some(birthday) = &cli.birthday {
+        config.birthday = Some(birthday.clone());
+    }
+
+    if let Some(expected_years) = cli.expected_years {
+        config.expected_years = Some(expected_years);
+    }
+
+    if cli.weeks {
+        config.show_weeks = Some(true);
+    } else if cli.no_weeks {
+        config.show_weeks = Some(false);
+    }
+
+    config
+}
+
 /// Main function to run the life progress program.
 ///
 /// This function orchestrates the execution of the life progress program.
@@ -289,10 +720,13 @@ fn display_summary_message(expected_days: i32, age_in_days: i32){
 /// main();
 /// ```
 fn main() {
-    let expected_years = 90; // Assume humans live 90 years
-    let expected_days = expected_years * 365;
+    let cli = Cli::parse();
+
+    let animate = !cli.no_animation && io::stdout().is_terminal();
 
-    display_welcome_message();
+    if !cli.json {
+        display_welcome_message();
+    }
 
     let mut config_dir = dirs::config_dir().expect("Failed to locate user's config directory");
     config_dir.push("yliw");
@@ -303,35 +737,81 @@ fn main() {
         user_config = toml::from_str(&toml_content)
             .expect("Failed to parse config file");
     }else{
-        user_config = UserConfig { birthday: None, show_weeks: Some(true) };
+        user_config = UserConfig {
+            birthday: None,
+            show_weeks: Some(true),
+            show_biorhythm: Some(false),
+            biorhythm_window: Some(30),
+            expected_years: None,
+            remaining_bar: Some(false),
+        };
     }
 
+    user_config = apply_cli_overrides(&cli, user_config);
+
     if user_config.birthday.is_none() {
+        if cli.json {
+            eprintln!("{{\"error\":\"No birthday configured. Pass --birthday DD-MM-YYYY or set it in config.toml.\"}}");
+            std::process::exit(1);
+        }
         let birthday = get_user_birthday();
         user_config.birthday = Some(birthday);
     }
 
-    let age_in_days;
-    if let Some(birthday) = &user_config.birthday {
-        age_in_days = get_age_in_days(birthday);
-    } else {
+    let expected_years = clamp_expected_years(user_config.expected_years.unwrap_or(90)); // Assume humans live 90 years
+    let remaining_bar = user_config.remaining_bar.unwrap_or(false);
+
+    let birthday = user_config.birthday.as_deref().unwrap_or_else(|| {
         println!("Birthday not found.");
         std::process::exit(1);
-    }
-    
+    });
+
+    let age_in_days = get_age_in_days(birthday).unwrap_or_else(|_| {
+        if cli.json {
+            eprintln!("{{\"error\":\"Unable to parse birthday. Use DD-MM-YYYY.\"}}");
+        } else {
+            println!("Unable to parse the date. Please use DD-MM-YYYY.");
+        }
+        std::process::exit(1);
+    });
+
+    let birth_date = Local::now().naive_local().date() - chrono::Duration::days(age_in_days as i64);
+    let expected_days = calculate_expected_days(birth_date, expected_years);
+
     let age_in_weeks = age_in_days as f64 / 7.0;
     let age_in_years = age_in_weeks as i32 / 52;
 
-    let mut life_progress_bar = create_progress_bar(expected_years as u64);
-    
+    if cli.json {
+        let stats = calculate_life_stats(expected_days, age_in_days);
+        let snapshot = LifeSnapshot {
+            age_in_days,
+            age_in_weeks,
+            age_in_years,
+            completion_percent: stats.completion_percent,
+            remaining_days: stats.remaining_days,
+            remaining_weeks: stats.remaining_weeks,
+            remaining_years: stats.remaining_years,
+            expected_years,
+        };
+        print_json_snapshot(&snapshot);
+        return;
+    }
+
+    let mut life_progress_bar = create_progress_bar(expected_years as u64, remaining_bar);
+
     println!("{}", format!("You are {} years old!", age_in_years).italic());
     println!();
 
-    display_progress_bar(&mut life_progress_bar, age_in_years);
+    display_progress_bar(&mut life_progress_bar, age_in_years, animate, remaining_bar, ANIMATION_BUDGET);
     display_summary_message(expected_days, age_in_days);
-    
+
     if user_config.show_weeks.unwrap_or(true){
-        print_life_in_weeks(age_in_weeks as usize);
+        print_life_in_weeks(age_in_weeks as usize, animate, ANIMATION_BUDGET);
+    }
+
+    if user_config.show_biorhythm.unwrap_or(false){
+        let biorhythm_window = user_config.biorhythm_window.unwrap_or(30);
+        print_biorhythm(age_in_days, biorhythm_window);
     }
 }
 
@@ -361,4 +841,110 @@ mod tests {
         let result = parse_date(date_str);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_get_age_in_days_invalid_returns_err(){
+        let result = get_age_in_days("not-a-date");
+        assert!(result.is_err());
+    }
+
+    fn default_user_config() -> UserConfig {
+        UserConfig {
+            birthday: Some("02-02-1990".to_string()),
+            show_weeks: Some(false),
+            show_biorhythm: Some(false),
+            biorhythm_window: Some(30),
+            expected_years: Some(77),
+            remaining_bar: Some(false),
+        }
+    }
+
+    #[test]
+    fn test_apply_cli_overrides_cli_wins_over_config(){
+        let cli = Cli::try_parse_from(["yliw", "--birthday", "01-01-2000", "--expected-years", "80"]).unwrap();
+        let resolved = apply_cli_overrides(&cli, default_user_config());
+        assert_eq!(resolved.birthday, Some("01-01-2000".to_string()));
+        assert_eq!(resolved.expected_years, Some(80));
+    }
+
+    #[test]
+    fn test_apply_cli_overrides_config_wins_over_default(){
+        let cli = Cli::try_parse_from(["yliw"]).unwrap();
+        let resolved = apply_cli_overrides(&cli, default_user_config());
+        assert_eq!(resolved.birthday, Some("02-02-1990".to_string()));
+        assert_eq!(resolved.expected_years, Some(77));
+        assert_eq!(resolved.show_weeks, Some(false));
+    }
+
+    #[test]
+    fn test_cli_weeks_and_no_weeks_conflict(){
+        let result = Cli::try_parse_from(["yliw", "--weeks", "--no-weeks"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_biorhythm_positions_range(){
+        let (physical, emotional, intellectual) = biorhythm_positions(10000);
+        assert!((0.0..=100.0).contains(&physical));
+        assert!((0.0..=100.0).contains(&emotional));
+        assert!((0.0..=100.0).contains(&intellectual));
+    }
+
+    #[test]
+    fn test_calculate_life_stats(){
+        let stats = calculate_life_stats(36500, 18250);
+        assert_eq!(stats.remaining_days, 18250);
+        assert_eq!(stats.remaining_years, 50);
+        assert_eq!(stats.remaining_weeks, 2600);
+        assert_eq!(stats.completion_percent, 50.0);
+    }
+
+    #[test]
+    fn test_add_years_handles_leap_day(){
+        let leap_birthday = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+        assert_eq!(add_years(leap_birthday, 1), NaiveDate::from_ymd_opt(2001, 2, 28).unwrap());
+        assert_eq!(add_years(leap_birthday, 4), NaiveDate::from_ymd_opt(2004, 2, 29).unwrap());
+    }
+
+    #[test]
+    fn test_calculate_expected_days_accounts_for_leap_years(){
+        let birth_date = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+        let expected_days = calculate_expected_days(birth_date, 90);
+        assert_eq!(expected_days, 32872);
+    }
+
+    #[test]
+    fn test_clamp_expected_years_bounds_extreme_values(){
+        assert_eq!(clamp_expected_years(-5), 1);
+        assert_eq!(clamp_expected_years(0), 1);
+        assert_eq!(clamp_expected_years(90), 90);
+        assert_eq!(clamp_expected_years(i32::MAX), 150);
+    }
+
+    #[test]
+    fn test_pace_step_never_overshoots_budget(){
+        let budget = Duration::from_millis(50);
+        let start = Instant::now();
+        for step in 0..5 {
+            pace_step(start, step, 5, budget);
+        }
+        assert!(start.elapsed() <= budget + Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_pace_step_skips_sleep_when_already_behind(){
+        let budget = Duration::from_millis(10);
+        let start = Instant::now() - Duration::from_secs(1);
+        let before = Instant::now();
+        pace_step(start, 0, 1, budget);
+        assert!(before.elapsed() < Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_biorhythm_positions_at_birth(){
+        let (physical, emotional, intellectual) = biorhythm_positions(0);
+        assert_eq!(physical, 50.0);
+        assert_eq!(emotional, 50.0);
+        assert_eq!(intellectual, 50.0);
+    }
 }